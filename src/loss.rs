@@ -0,0 +1,23 @@
+use crate::value::Value;
+
+// subtracts the max logit before exponentiating so it doesn't overflow exp
+pub fn softmax(logits: &[Value]) -> Vec<Value> {
+    let max = logits
+        .iter()
+        .map(|v| v.data())
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    let shifted: Vec<Value> = logits.iter().map(|v| (v - &Value::new(max)).exp()).collect();
+
+    let mut sum = shifted[0].clone();
+    for s in shifted.iter().skip(1) {
+        sum = sum + s.clone();
+    }
+
+    shifted.into_iter().map(|s| s / sum.clone()).collect()
+}
+
+pub fn cross_entropy(logits: &[Value], target_index: usize) -> Value {
+    let probs = softmax(logits);
+    -probs[target_index].ln()
+}
@@ -1,12 +1,18 @@
+mod loss;
+mod matrix;
 mod neuron;
+mod optimizer;
 mod value;
-use neuron::MLP;
+use loss::cross_entropy;
+use matrix::Matrix;
+use neuron::{Activation, MLP};
+use optimizer::{Adam, Optimizer, Sgd};
 use std::error::Error;
 use std::fs::File;
 use value::Value;
 
 #[allow(dead_code)]
-fn load_data(path: &str) -> Result<(Vec<Vec<Value>>, Vec<Value>), Box<dyn Error>> {
+fn load_data(path: &str) -> Result<(Vec<Vec<Value>>, Vec<usize>), Box<dyn Error>> {
     let file = File::open(path)?;
     let mut rdr = csv::Reader::from_reader(file);
 
@@ -22,14 +28,14 @@ fn load_data(path: &str) -> Result<(Vec<Vec<Value>>, Vec<Value>), Box<dyn Error>
         let label: f64 = record[2].parse()?;
 
         inputs.push(vec![Value::new(x), Value::new(y)]);
-        targets.push(Value::new(label));
+        targets.push(if label > 0.0 { 1 } else { 0 });
     }
 
     Ok((inputs, targets))
 }
 
 #[allow(dead_code)]
-fn mock_inputs() -> Result<(Vec<Vec<Value>>, Vec<Value>), Box<dyn Error>> {
+fn mock_inputs() -> Result<(Vec<Vec<Value>>, Vec<usize>), Box<dyn Error>> {
     Ok((
         vec![
             vec![Value::new(2.0), Value::new(3.0), Value::new(-1.0)],
@@ -37,40 +43,38 @@ fn mock_inputs() -> Result<(Vec<Vec<Value>>, Vec<Value>), Box<dyn Error>> {
             vec![Value::new(0.5), Value::new(1.0), Value::new(1.0)],
             vec![Value::new(1.0), Value::new(1.0), Value::new(-1.0)],
         ],
-        vec![
-            Value::new(1.0),
-            Value::new(-1.0),
-            Value::new(-1.0),
-            Value::new(1.0),
-        ],
+        vec![1, 0, 0, 1],
     ))
 }
 
 fn main() {
     let data = load_data("dataset.csv");
-    let mlp = MLP::new(2, vec![16, 16, 16, 1]);
+    let mlp = MLP::load("model.json")
+        .unwrap_or_else(|_| MLP::new(2, vec![16, 16, 16, 2], Activation::Tanh));
 
     // let data = mock_inputs();
-    // let mlp = MLP::new(3, vec![4, 4, 1]);
+    // let mlp = MLP::new(3, vec![4, 4, 2], Activation::Tanh);
     let (inputs, targets) = data.expect("Failed to open file");
+    let batch = Matrix::from_rows(inputs.clone());
+    // set OPTIMIZER=adam to train with Adam instead of the default Sgd
+    let use_adam = std::env::var("OPTIMIZER").is_ok_and(|v| v.eq_ignore_ascii_case("adam"));
+    let optimizer: Box<dyn Optimizer> = if use_adam {
+        Box::new(Adam::new(0.01, 0.9, 0.999, 1e-8))
+    } else {
+        Box::new(Sgd::new(0.05, 0.0))
+    };
     loop {
         // println!("MLP: {:?}", mlp);
-        let mut outputs = Vec::with_capacity(inputs.len());
-        for input in inputs.iter() {
-            outputs.push(mlp.forward(input))
-        }
-        let first_out = outputs[0].clone();
-        println!("Sample prediction {:?}", first_out);
-        let tmp = &first_out[0] - &targets[0];
-        let mut loss = tmp.pow(2.0);
-        for (out, target) in outputs.iter().zip(targets.iter()).skip(1) {
-            let tmp = &out[0] - target;
-            loss = loss + tmp.pow(2.0);
+        let outputs = mlp.forward_batch(&batch);
+        println!("Sample prediction {:?}", outputs.row(0));
+        let mut loss = cross_entropy(outputs.row(0), targets[0]);
+        for (row, target) in (1..outputs.rows).zip(targets.iter().skip(1)) {
+            loss = loss + cross_entropy(outputs.row(row), *target);
         }
 
-        loss = loss / (outputs.len() as f64);
+        loss = loss / (outputs.rows as f64);
 
-        if loss.data() < 0.0001 && loss.data() > -0.001 {
+        if loss.data() < 0.0001 {
             break;
         }
 
@@ -79,9 +83,10 @@ fn main() {
             loss, mlp.layers[0].neurons[0].weights[0]
         );
         println!("Doing backprop over loss");
+        mlp.zero_grad();
         loss.backprop();
         // println!("Params: {:?}", mlp.parameters());
-        mlp.descend(0.05);
+        optimizer.step(&mlp.parameters());
         println!("Updated weight: {:?}", mlp.layers[0].neurons[0].weights[0]);
         // thread::sleep(time::Duration::from_millis(1000));
     }
@@ -89,4 +94,6 @@ fn main() {
     for input in inputs.iter() {
         println!("\t{:?} : {:?}", input, mlp.forward(input))
     }
+
+    mlp.save("model.json").expect("Failed to save model");
 }
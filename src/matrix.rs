@@ -0,0 +1,60 @@
+use crate::value::Value;
+
+// row-major matrix of Values; matmul is what wires the autodiff graph
+#[derive(Clone)]
+pub struct Matrix {
+    pub rows: usize,
+    pub cols: usize,
+    pub data: Vec<Value>,
+}
+
+impl Matrix {
+    pub fn new(rows: usize, cols: usize, data: Vec<Value>) -> Self {
+        assert_eq!(data.len(), rows * cols, "data length must be rows * cols");
+        Matrix { rows, cols, data }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<Value>>) -> Self {
+        let num_rows = rows.len();
+        let num_cols = rows.first().map_or(0, |row| row.len());
+        let data = rows.into_iter().flatten().collect();
+        Matrix::new(num_rows, num_cols, data)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &Value {
+        &self.data[row * self.cols + col]
+    }
+
+    pub fn row(&self, row: usize) -> &[Value] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let mut data = Vec::with_capacity(self.data.len());
+        for col in 0..self.cols {
+            for row in 0..self.rows {
+                data.push(self.get(row, col).clone());
+            }
+        }
+        Matrix::new(self.cols, self.rows, data)
+    }
+
+    pub fn matmul(&self, other: &Matrix) -> Matrix {
+        assert_eq!(
+            self.cols, other.rows,
+            "inner matmul dimensions must match"
+        );
+
+        let mut data = Vec::with_capacity(self.rows * other.cols);
+        for r in 0..self.rows {
+            for c in 0..other.cols {
+                let mut sum = self.get(r, 0) * other.get(0, c);
+                for k in 1..self.cols {
+                    sum = sum + (self.get(r, k) * other.get(k, c));
+                }
+                data.push(sum);
+            }
+        }
+        Matrix::new(self.rows, other.cols, data)
+    }
+}
@@ -1,6 +1,31 @@
+use crate::matrix::Matrix;
 use crate::value::Value;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
 use std::fmt;
+use std::fs::File;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Activation {
+    Tanh,
+    Relu,
+    Sigmoid,
+    LeakyRelu(f64),
+    Identity,
+}
+
+impl Activation {
+    fn apply(&self, value: &Value) -> Value {
+        match self {
+            Activation::Tanh => value.tanh(),
+            Activation::Relu => value.relu(),
+            Activation::Sigmoid => value.sigmoid(),
+            Activation::LeakyRelu(alpha) => value.leaky_relu(*alpha),
+            Activation::Identity => value.identity(),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct Neuron {
@@ -8,10 +33,11 @@ pub struct Neuron {
     pub input_size: usize,
     pub bias: Value,
     pub weights: Vec<Value>,
+    pub activation: Activation,
 }
 
 impl Neuron {
-    pub fn new(size: usize) -> Self {
+    pub fn new(size: usize, activation: Activation) -> Self {
         let mut rng = rand::rng();
         let mut vec = Vec::with_capacity(size);
         for _ in 0..size {
@@ -21,17 +47,10 @@ impl Neuron {
             input_size: size,
             bias: Value::new(rng.random_range(-1.0..1.0)),
             weights: vec,
+            activation,
         }
     }
 
-    pub fn call(&self, inputs: &Vec<Value>) -> Value {
-        let mut sum = self.bias.clone();
-        for (i, w) in inputs.iter().zip(&self.weights) {
-            sum = sum + (w * i)
-        }
-        sum.tanh()
-    }
-
     pub fn parameters(&self) -> Vec<Value> {
         let mut p = self.weights.clone();
         p.push(self.bias.clone());
@@ -60,10 +79,10 @@ pub struct Layer {
 }
 
 impl Layer {
-    pub fn new(input_size: usize, output_size: usize) -> Self {
+    pub fn new(input_size: usize, output_size: usize, activation: Activation) -> Self {
         let mut ns = Vec::with_capacity(output_size);
         for _ in 0..output_size {
-            ns.push(Neuron::new(input_size));
+            ns.push(Neuron::new(input_size, activation));
         }
         Layer {
             neurons: ns,
@@ -72,12 +91,18 @@ impl Layer {
         }
     }
 
-    pub fn forward(&self, inputs: Vec<Value>) -> Vec<Value> {
-        let mut outputs = Vec::with_capacity(self.output_size);
-        for neuron in self.neurons.iter() {
-            outputs.push(neuron.call(&inputs));
+    // inputs · Wᵀ + b for a whole batch in one matmul per layer
+    pub fn forward_batch(&self, inputs: &Matrix) -> Matrix {
+        let weights = Matrix::from_rows(self.neurons.iter().map(|n| n.weights.clone()).collect());
+        let mut out = inputs.matmul(&weights.transpose());
+
+        for r in 0..out.rows {
+            for (c, neuron) in self.neurons.iter().enumerate() {
+                let idx = r * out.cols + c;
+                out.data[idx] = neuron.activation.apply(&(&out.data[idx] + &neuron.bias));
+            }
         }
-        return outputs;
+        out
     }
 
     pub fn parameters(&self) -> Vec<Value> {
@@ -106,11 +131,11 @@ pub struct MLP {
 }
 
 impl MLP {
-    pub fn new(inputs_size: usize, layer_sizes: Vec<usize>) -> Self {
+    pub fn new(inputs_size: usize, layer_sizes: Vec<usize>, activation: Activation) -> Self {
         let mut layers = Vec::with_capacity(layer_sizes.len());
         let mut prev_size = inputs_size;
         for size in layer_sizes {
-            layers.push(Layer::new(prev_size, size));
+            layers.push(Layer::new(prev_size, size, activation));
             prev_size = size;
         }
         MLP {
@@ -120,9 +145,16 @@ impl MLP {
     }
 
     pub fn forward(&self, inputs: &Vec<Value>) -> Vec<Value> {
+        let cols = inputs.len();
+        self.forward_batch(&Matrix::new(1, cols, inputs.clone())).data
+    }
+
+    // runs a whole batch through every layer as one matmul per layer
+    // instead of one forward call per sample
+    pub fn forward_batch(&self, inputs: &Matrix) -> Matrix {
         let mut previous_out = inputs.clone();
         for layer in self.layers.iter() {
-            previous_out = layer.forward(previous_out);
+            previous_out = layer.forward_batch(&previous_out);
         }
         previous_out
     }
@@ -130,9 +162,10 @@ impl MLP {
     pub fn parameters(&self) -> Vec<Value> {
         self.layers.iter().flat_map(|l| l.parameters()).collect()
     }
-    pub fn descend(&self, learning_rate: f64) -> () {
+
+    pub fn zero_grad(&self) -> () {
         for p in self.parameters().iter() {
-            p.update(learning_rate);
+            p.zero_grad();
         }
     }
 }
@@ -146,3 +179,101 @@ impl fmt::Debug for MLP {
         Ok(())
     }
 }
+
+#[derive(Serialize, Deserialize)]
+struct NeuronData {
+    input_size: usize,
+    bias: f64,
+    weights: Vec<f64>,
+    activation: Activation,
+}
+
+impl From<&Neuron> for NeuronData {
+    fn from(neuron: &Neuron) -> Self {
+        NeuronData {
+            input_size: neuron.input_size,
+            bias: neuron.bias.data(),
+            weights: neuron.weights.iter().map(|w| w.data()).collect(),
+            activation: neuron.activation,
+        }
+    }
+}
+
+impl From<NeuronData> for Neuron {
+    fn from(data: NeuronData) -> Self {
+        Neuron {
+            input_size: data.input_size,
+            bias: Value::new(data.bias),
+            weights: data.weights.into_iter().map(Value::new).collect(),
+            activation: data.activation,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct LayerData {
+    input_size: usize,
+    output_size: usize,
+    neurons: Vec<NeuronData>,
+}
+
+impl From<&Layer> for LayerData {
+    fn from(layer: &Layer) -> Self {
+        LayerData {
+            input_size: layer.input_size,
+            output_size: layer.output_size,
+            neurons: layer.neurons.iter().map(NeuronData::from).collect(),
+        }
+    }
+}
+
+impl From<LayerData> for Layer {
+    fn from(data: LayerData) -> Self {
+        Layer {
+            input_size: data.input_size,
+            output_size: data.output_size,
+            neurons: data.neurons.into_iter().map(Neuron::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct MLPData {
+    input_size: usize,
+    layers: Vec<LayerData>,
+}
+
+impl From<&MLP> for MLPData {
+    fn from(mlp: &MLP) -> Self {
+        MLPData {
+            input_size: mlp.input_size,
+            layers: mlp.layers.iter().map(LayerData::from).collect(),
+        }
+    }
+}
+
+impl From<MLPData> for MLP {
+    fn from(data: MLPData) -> Self {
+        MLP {
+            input_size: data.input_size,
+            layers: data.layers.into_iter().map(Layer::from).collect(),
+        }
+    }
+}
+
+impl MLP {
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        let data = MLPData::from(self);
+        let file = File::create(path)?;
+        serde_json::to_writer_pretty(file, &data)?;
+        Ok(())
+    }
+
+    // rebuilds fresh leaf Values so the result can immediately run forward
+    // or resume training
+    pub fn load(path: &str) -> Result<MLP, Box<dyn Error>> {
+        let file = File::open(path)?;
+        let data: MLPData = serde_json::from_reader(file)?;
+        Ok(MLP::from(data))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::value::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+// reads each parameter's accumulated grad and writes back an updated data;
+// implementations key their per-parameter state by Value::ptr_key since the
+// same parameter Value is reused across every call to step
+pub trait Optimizer {
+    fn step(&self, params: &[Value]);
+}
+
+pub struct Sgd {
+    pub lr: f64,
+    pub momentum: f64,
+    velocity: RefCell<HashMap<usize, f64>>,
+}
+
+impl Sgd {
+    pub fn new(lr: f64, momentum: f64) -> Self {
+        Sgd {
+            lr,
+            momentum,
+            velocity: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
+impl Optimizer for Sgd {
+    fn step(&self, params: &[Value]) {
+        let mut velocity = self.velocity.borrow_mut();
+        for p in params {
+            let grad = p.grad().unwrap_or(0.0);
+            let v = velocity.entry(p.ptr_key()).or_insert(0.0);
+            *v = self.momentum * *v + grad;
+            p.set_data(p.data() - self.lr * *v);
+        }
+    }
+}
+
+struct AdamState {
+    m: f64,
+    v: f64,
+    t: i32,
+}
+
+pub struct Adam {
+    pub lr: f64,
+    pub beta1: f64,
+    pub beta2: f64,
+    pub eps: f64,
+    state: RefCell<HashMap<usize, AdamState>>,
+}
+
+impl Adam {
+    pub fn new(lr: f64, beta1: f64, beta2: f64, eps: f64) -> Self {
+        Adam {
+            lr,
+            beta1,
+            beta2,
+            eps,
+            state: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
+impl Optimizer for Adam {
+    fn step(&self, params: &[Value]) {
+        let mut state = self.state.borrow_mut();
+        for p in params {
+            let grad = p.grad().unwrap_or(0.0);
+            let s = state.entry(p.ptr_key()).or_insert(AdamState {
+                m: 0.0,
+                v: 0.0,
+                t: 0,
+            });
+
+            s.t += 1;
+            s.m = self.beta1 * s.m + (1.0 - self.beta1) * grad;
+            s.v = self.beta2 * s.v + (1.0 - self.beta2) * grad * grad;
+
+            let m_hat = s.m / (1.0 - self.beta1.powi(s.t));
+            let v_hat = s.v / (1.0 - self.beta2.powi(s.t));
+
+            p.set_data(p.data() - self.lr * m_hat / (v_hat.sqrt() + self.eps));
+        }
+    }
+}
@@ -12,6 +12,12 @@ pub enum Oper {
     Pow(f64),
     Leaf,
     Tanh,
+    Relu,
+    Sigmoid,
+    LeakyRelu(f64),
+    Identity,
+    Exp,
+    Log,
 }
 
 struct ValueData {
@@ -43,9 +49,17 @@ impl Value {
         })))
     }
 
-    pub fn update(&self, learning_rate: f64) -> () {
-        let mut param = self.0.borrow_mut();
-        param.data += -learning_rate * param.grad.unwrap()
+    pub fn set_data(&self, data: f64) -> () {
+        self.0.borrow_mut().data = data;
+    }
+
+    pub fn zero_grad(&self) -> () {
+        self.0.borrow_mut().grad = None;
+    }
+
+    // stable identity across clones, used by optimizers to key per-parameter state
+    pub fn ptr_key(&self) -> usize {
+        Rc::as_ptr(&self.0) as usize
     }
 
     pub fn backprop(&self) -> () {
@@ -115,6 +129,42 @@ impl Value {
                     let mut child = data._prev[0].0.borrow_mut();
                     child.grad = Some(child.grad.unwrap_or(0.0) + d * grad);
                 }
+                Oper::Relu => {
+                    let d = if data.data > 0.0 { 1.0 } else { 0.0 };
+
+                    let mut child = data._prev[0].0.borrow_mut();
+                    child.grad = Some(child.grad.unwrap_or(0.0) + d * grad);
+                }
+                Oper::LeakyRelu(alpha) => {
+                    let d = if data.data > 0.0 { 1.0 } else { alpha };
+
+                    let mut child = data._prev[0].0.borrow_mut();
+                    child.grad = Some(child.grad.unwrap_or(0.0) + d * grad);
+                }
+                Oper::Sigmoid => {
+                    let s = data.data; // sigmoid(x)
+                    let d = s * (1.0 - s); // derivative is s * (1 - s)
+
+                    let mut child = data._prev[0].0.borrow_mut();
+                    child.grad = Some(child.grad.unwrap_or(0.0) + d * grad);
+                }
+                Oper::Identity => {
+                    let mut child = data._prev[0].0.borrow_mut();
+                    child.grad = Some(child.grad.unwrap_or(0.0) + grad);
+                }
+                Oper::Exp => {
+                    let out = data.data; // e^x
+
+                    let mut child = data._prev[0].0.borrow_mut();
+                    child.grad = Some(child.grad.unwrap_or(0.0) + out * grad);
+                }
+                Oper::Log => {
+                    let child_rc = &data._prev[0];
+                    let child_data = child_rc.data();
+
+                    let mut child = child_rc.0.borrow_mut();
+                    child.grad = Some(child.grad.unwrap_or(0.0) + (1.0 / child_data) * grad);
+                }
                 Oper::Leaf => {}
             };
         }
@@ -153,6 +203,67 @@ impl Value {
             _op: Oper::Tanh,
         })))
     }
+
+    pub fn relu(&self) -> Self {
+        let data = self.data();
+        Value(Rc::new(RefCell::new(ValueData {
+            data: data.max(0.0),
+            grad: None,
+            _prev: vec![self.clone()],
+            _op: Oper::Relu,
+        })))
+    }
+
+    pub fn leaky_relu(&self, alpha: f64) -> Self {
+        let data = self.data();
+        let out = if data > 0.0 { data } else { alpha * data };
+        Value(Rc::new(RefCell::new(ValueData {
+            data: out,
+            grad: None,
+            _prev: vec![self.clone()],
+            _op: Oper::LeakyRelu(alpha),
+        })))
+    }
+
+    pub fn sigmoid(&self) -> Self {
+        let data = self.data();
+        let out = 1.0 / (1.0 + (-data).exp());
+        Value(Rc::new(RefCell::new(ValueData {
+            data: out,
+            grad: None,
+            _prev: vec![self.clone()],
+            _op: Oper::Sigmoid,
+        })))
+    }
+
+    pub fn identity(&self) -> Self {
+        Value(Rc::new(RefCell::new(ValueData {
+            data: self.data(),
+            grad: None,
+            _prev: vec![self.clone()],
+            _op: Oper::Identity,
+        })))
+    }
+
+    pub fn exp(&self) -> Self {
+        let data = self.data();
+        Value(Rc::new(RefCell::new(ValueData {
+            data: data.exp(),
+            grad: None,
+            _prev: vec![self.clone()],
+            _op: Oper::Exp,
+        })))
+    }
+
+    pub fn ln(&self) -> Self {
+        let data = self.data();
+        Value(Rc::new(RefCell::new(ValueData {
+            data: data.ln(),
+            grad: None,
+            _prev: vec![self.clone()],
+            _op: Oper::Log,
+        })))
+    }
 }
 
 impl fmt::Debug for Value {